@@ -0,0 +1,317 @@
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+use core::slice;
+
+use crate::{slice_assume_init_mut, slice_assume_init_ref};
+
+/// A fixed-capacity circular buffer whose capacity is a compile-time constant.
+///
+/// Unlike [`crate::CircleBuffer`], `CircleArray` stores its elements inline
+/// in a `[MaybeUninit<T>; N]` rather than on the heap, so it has no
+/// allocator requirement, is available without the `alloc`/`std` features,
+/// and can be constructed in a `const` context (e.g. to live in a `static`).
+pub struct CircleArray<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    start: usize,
+    size: usize,
+}
+
+impl<T, const N: usize> CircleArray<T, N> {
+    /// Creates a new empty `CircleArray<T, N>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleArray;
+    ///
+    /// static RING: CircleArray<i32, 4> = CircleArray::new();
+    /// ```
+    pub const fn new() -> Self {
+        CircleArray {
+            // Safety: a `MaybeUninit<[MaybeUninit<T>; N]>` is valid for any
+            // byte pattern, including uninitialized, because its elements
+            // are themselves `MaybeUninit<T>`.
+            buf: unsafe { MaybeUninit::uninit().assume_init() },
+            start: 0,
+            size: 0,
+        }
+    }
+
+    /// Returns the capacity of the buffer.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the current number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns true if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Pushes a new element into the buffer. Alias for [`CircleArray::push_back`].
+    pub fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    /// Pushes a new element onto the back of the buffer.
+    /// Once the capacity is reached, pushing new items will overwrite the front.
+    pub fn push_back(&mut self, value: T) {
+        if N == 0 {
+            // Nothing to store; drop `value` immediately, matching
+            // `CircleBuffer::push_back`'s handling of zero capacity.
+            return;
+        }
+        if self.size < N {
+            let index = (self.start + self.size) % N;
+            self.buf[index] = MaybeUninit::new(value);
+            self.size += 1;
+        } else {
+            unsafe {
+                core::ptr::drop_in_place(self.buf[self.start].as_mut_ptr());
+            }
+            self.buf[self.start] = MaybeUninit::new(value);
+            self.start = (self.start + 1) % N;
+        }
+    }
+
+    /// Pushes a new element onto the front of the buffer.
+    /// Once the capacity is reached, pushing new items will overwrite the back.
+    pub fn push_front(&mut self, value: T) {
+        if N == 0 {
+            // Nothing to store; drop `value` immediately, matching
+            // `CircleBuffer::push_front`'s handling of zero capacity.
+            return;
+        }
+        if self.size < N {
+            self.start = (self.start + N - 1) % N;
+            self.buf[self.start] = MaybeUninit::new(value);
+            self.size += 1;
+        } else {
+            let back_index = (self.start + self.size - 1) % N;
+            unsafe {
+                core::ptr::drop_in_place(self.buf[back_index].as_mut_ptr());
+            }
+            self.start = (self.start + N - 1) % N;
+            self.buf[self.start] = MaybeUninit::new(value);
+        }
+    }
+
+    /// Removes and returns the element at the front of the buffer, or `None`
+    /// if the buffer is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
+        }
+
+        let value = self.take(self.start);
+        self.start = (self.start + 1) % N;
+        self.size -= 1;
+        Some(value)
+    }
+
+    /// Removes and returns the element at the back of the buffer, or `None`
+    /// if the buffer is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
+        }
+
+        let index = (self.start + self.size - 1) % N;
+        let value = self.take(index);
+        self.size -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the front element, or `None` if the buffer is empty.
+    pub fn front(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[0])
+        }
+    }
+
+    /// Returns a reference to the back element, or `None` if the buffer is empty.
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[self.size - 1])
+        }
+    }
+
+    /// Returns a mutable reference to the front element, or `None` if the buffer is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&mut self[0])
+        }
+    }
+
+    /// Returns a mutable reference to the back element, or `None` if the buffer is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = self.size - 1;
+            Some(&mut self[index])
+        }
+    }
+
+    /// Takes ownership of the value stored at `index`, leaving that slot
+    /// uninitialized. The caller is responsible for updating `start`/`size`
+    /// so the slot isn't considered live (and isn't dropped again).
+    fn take(&mut self, index: usize) -> T {
+        let slot = core::mem::replace(&mut self.buf[index], MaybeUninit::uninit());
+        unsafe { slot.assume_init() }
+    }
+
+    /// Returns the buffer's contents as two slices.
+    ///
+    /// See [`crate::CircleBuffer::as_slices`] for how the split works.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = core::cmp::min(self.start + self.size, N);
+        let first = unsafe { slice_assume_init_ref(&self.buf[self.start..end]) };
+        let overflow = self.start + self.size - end;
+        let second = unsafe { slice_assume_init_ref(&self.buf[0..overflow]) };
+        (first, second)
+    }
+
+    /// Returns the buffer's contents as two mutable slices.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let end = core::cmp::min(self.start + self.size, N);
+        let overflow = self.start + self.size - end;
+        let (wrapped, rest) = self.buf.split_at_mut(self.start);
+        let first = unsafe { slice_assume_init_mut(&mut rest[..end - self.start]) };
+        let second = unsafe { slice_assume_init_mut(&mut wrapped[..overflow]) };
+        (first, second)
+    }
+
+    /// Returns an iterator over the buffer's contents, from oldest to newest.
+    pub fn iter(&self) -> core::iter::Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second.iter())
+    }
+
+    /// Returns a mutable iterator over the buffer's contents, from oldest to newest.
+    pub fn iter_mut(&mut self) -> core::iter::Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let (first, second) = self.as_mut_slices();
+        first.iter_mut().chain(second.iter_mut())
+    }
+}
+
+impl<T, const N: usize> Default for CircleArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for CircleArray<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        assert!(index < self.size);
+        let (first, second) = self.as_slices();
+        if index < first.len() {
+            &first[index]
+        } else {
+            &second[index - first.len()]
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for CircleArray<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        assert!(index < self.size);
+        let (first, second) = self.as_mut_slices();
+        let first_len = first.len();
+        if index < first_len {
+            &mut first[index]
+        } else {
+            &mut second[index - first_len]
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for CircleArray<T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.size {
+            let index = (self.start + i) % N;
+            unsafe {
+                core::ptr::drop_in_place(self.buf[index].as_mut_ptr());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_overwrite() {
+        let mut buffer: CircleArray<i32, 3> = CircleArray::new();
+        buffer.push(1);
+        buffer.push(2);
+        buffer.push(3);
+        buffer.push(4);
+        assert_eq!(buffer.as_slices(), (&[2, 3][..], &[4][..]));
+    }
+
+    #[test]
+    fn test_push_front_and_pop() {
+        let mut buffer: CircleArray<i32, 3> = CircleArray::new();
+        buffer.push_front(1);
+        buffer.push_front(2);
+        buffer.push_front(3);
+        assert_eq!(buffer.as_slices(), (&[3, 2, 1][..], &[][..]));
+
+        assert_eq!(buffer.pop_back(), Some(1));
+        assert_eq!(buffer.pop_front(), Some(3));
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_push_zero_capacity_is_a_noop() {
+        let mut buffer: CircleArray<i32, 0> = CircleArray::new();
+        buffer.push_back(1);
+        buffer.push_front(2);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_const_new_in_static() {
+        static RING: CircleArray<i32, 4> = CircleArray::new();
+        assert_eq!(RING.capacity(), 4);
+        assert!(RING.is_empty());
+    }
+
+    #[test]
+    fn test_drop_runs_for_every_live_element() {
+        use core::cell::Cell;
+
+        struct DropCounter<'a>(&'a Cell<i32>);
+        impl<'a> Drop for DropCounter<'a> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        {
+            let mut buffer: CircleArray<DropCounter, 3> = CircleArray::new();
+            buffer.push(DropCounter(&drops));
+            buffer.push(DropCounter(&drops));
+            buffer.push(DropCounter(&drops));
+            buffer.push(DropCounter(&drops));
+        }
+        assert_eq!(drops.get(), 4);
+    }
+}
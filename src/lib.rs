@@ -1,14 +1,78 @@
-use std::ops::{Index, IndexMut};
-use std::slice::{Iter, IterMut};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A fixed-capacity circular buffer.
+//!
+//! [`CircleBuffer`] is a heap-allocated ring that can grow its capacity only
+//! by being re-created; it requires the `alloc` cargo feature (the `std`
+//! feature enables `alloc` and additionally implements `std::io::{Read,
+//! Write, BufRead}` for `CircleBuffer<u8>`). [`CircleArray`] is its
+//! `#![no_std]`-friendly sibling: the same push/pop/iterator/slice API, but
+//! backed by an inline `[MaybeUninit<T>; N]` with no allocator required, so
+//! it can live in a `static`. The `serde` feature implements `Serialize`/
+//! `Deserialize` for `CircleBuffer<T>`, encoding its logical contents
+//! (oldest to newest) as a plain sequence.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+
+use core::mem::MaybeUninit;
+#[cfg(feature = "alloc")]
+use core::ops::{Bound, Index, IndexMut, RangeBounds};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::slice;
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+mod circle_array;
+pub use circle_array::CircleArray;
+
+/// Returns the initialized portion of `slice` as `&[T]`.
+///
+/// # Safety
+///
+/// Every element of `slice` must already be initialized.
+unsafe fn slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
+    &*(slice as *const [MaybeUninit<T>] as *const [T])
+}
+
+/// Returns the initialized portion of `slice` as `&mut [T]`.
+///
+/// # Safety
+///
+/// Every element of `slice` must already be initialized.
+unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+}
 
 /// A circular buffer.
-pub struct CircleBuffer<T> where T: Clone {
+///
+/// Internally this holds exactly `capacity` slots of storage. Once full,
+/// pushing a new element overwrites the oldest one. Because the live
+/// elements may wrap around the end of the backing storage, the contents
+/// are exposed as up to two slices via [`CircleBuffer::as_slices`] rather
+/// than a single contiguous one.
+///
+/// This type is heap-allocated and requires the `alloc` (or `std`) cargo
+/// feature. For a `#![no_std]`, allocation-free ring with a compile-time
+/// capacity, see [`CircleArray`].
+#[cfg(feature = "alloc")]
+pub struct CircleBuffer<T> {
     capacity: usize,
-    vec: Vec<T>,
-    cur_start: usize,
+    buf: Vec<MaybeUninit<T>>,
+    start: usize,
+    size: usize,
 }
 
-impl<T> CircleBuffer<T> where T: Clone {
+#[cfg(feature = "alloc")]
+impl<T> CircleBuffer<T> {
     /// Creates a new empty `CircleBuffer<T>` with capacity.
     ///
     /// # Examples
@@ -19,10 +83,16 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
     /// ```
     pub fn with_capacity(capacity: usize) -> CircleBuffer<T> {
+        let mut buf = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            buf.push(MaybeUninit::uninit());
+        }
+
         CircleBuffer {
-            capacity: capacity,
-            vec: Vec::with_capacity(capacity * 2 - 1),
-            cur_start: 0,
+            capacity,
+            buf,
+            start: 0,
+            size: 0,
         }
     }
 
@@ -43,7 +113,7 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// Returns the current number of elements in the buffer.
     ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use circle_buffer::CircleBuffer;
     ///
@@ -55,12 +125,7 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// assert_eq!(cbuf.len(), 3);
     /// ```
     pub fn len(&self) -> usize {
-        let len = self.vec.len();
-        if len > self.capacity {
-            self.capacity
-        }else{
-            len
-        }
+        self.size
     }
 
     /// Returns true if the buffer contains no elements.
@@ -77,14 +142,16 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// assert!(!cbuf.is_empty())
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.vec.len() == 0
+        self.size == 0
     }
 
     /// Pushes a new element into the buffer.
     /// Once the capacity is reached, pushing new items will overwrite old ones.
     ///
+    /// This is an alias for [`CircleBuffer::push_back`].
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use circle_buffer::CircleBuffer;
     ///
@@ -101,59 +168,292 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// assert_eq!(cbuf[2], 4);
     ///
     /// let mut sum = 0;
-    /// for x in cbuf.as_slice() {
+    /// for x in cbuf.iter() {
     ///     sum += x;
     /// }
     /// assert_eq!(sum, 9);
     /// ```
-    pub fn push(&mut self, value: T){
-        if self.vec.len() < self.capacity {
-            self.vec.push(value);
-
-        } else if self.vec.len() < self.capacity * 2 - 1 {
-            let v = value.clone();
-            self.vec.push(value);
-            self.vec[self.cur_start] = v;
-
-            self.cur_start += 1;
+    pub fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
 
+    /// Pushes a new element onto the back of the buffer.
+    /// Once the capacity is reached, pushing new items will overwrite the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push_back(1);
+    /// cbuf.push_back(2);
+    /// cbuf.push_back(3);
+    /// cbuf.push_back(4);
+    ///
+    /// assert_eq!(cbuf[0], 2);
+    /// assert_eq!(cbuf[1], 3);
+    /// assert_eq!(cbuf[2], 4);
+    /// ```
+    pub fn push_back(&mut self, value: T) {
+        if self.capacity == 0 {
+            // Nothing to store; drop `value` immediately, matching
+            // `io::Write::write`'s handling of a zero-capacity buffer.
+            return;
+        }
+        if self.size < self.capacity {
+            let index = (self.start + self.size) % self.capacity;
+            self.buf[index] = MaybeUninit::new(value);
+            self.size += 1;
         } else {
-            let v = value.clone();
+            unsafe {
+                core::ptr::drop_in_place(self.buf[self.start].as_mut_ptr());
+            }
+            self.buf[self.start] = MaybeUninit::new(value);
+            self.start = (self.start + 1) % self.capacity;
+        }
+    }
 
-            let index = self.cur_start + self.capacity;
-            if index < self.capacity * 2 - 1 {
-                self.vec[index] = value;
+    /// Pushes a new element onto the front of the buffer.
+    /// Once the capacity is reached, pushing new items will overwrite the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push_front(1);
+    /// cbuf.push_front(2);
+    /// cbuf.push_front(3);
+    /// cbuf.push_front(4);
+    ///
+    /// assert_eq!(cbuf[0], 4);
+    /// assert_eq!(cbuf[1], 3);
+    /// assert_eq!(cbuf[2], 2);
+    /// ```
+    pub fn push_front(&mut self, value: T) {
+        if self.capacity == 0 {
+            // Nothing to store; drop `value` immediately, matching
+            // `io::Write::write`'s handling of a zero-capacity buffer.
+            return;
+        }
+        if self.size < self.capacity {
+            self.start = (self.start + self.capacity - 1) % self.capacity;
+            self.buf[self.start] = MaybeUninit::new(value);
+            self.size += 1;
+        } else {
+            let back_index = (self.start + self.size - 1) % self.capacity;
+            unsafe {
+                core::ptr::drop_in_place(self.buf[back_index].as_mut_ptr());
             }
+            self.start = (self.start + self.capacity - 1) % self.capacity;
+            self.buf[self.start] = MaybeUninit::new(value);
+        }
+    }
+
+    /// Removes and returns the element at the front of the buffer, or `None`
+    /// if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push_back(1);
+    /// cbuf.push_back(2);
+    ///
+    /// assert_eq!(cbuf.pop_front(), Some(1));
+    /// assert_eq!(cbuf.pop_front(), Some(2));
+    /// assert_eq!(cbuf.pop_front(), None);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
+        }
 
-            self.vec[self.cur_start] = v;
+        let value = self.take(self.start);
+        self.start = (self.start + 1) % self.capacity;
+        self.size -= 1;
+        Some(value)
+    }
 
-            self.cur_start += 1;
-            if self.cur_start >= self.capacity {
-                self.cur_start = 0;
-            }
+    /// Removes and returns the element at the back of the buffer, or `None`
+    /// if the buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push_back(1);
+    /// cbuf.push_back(2);
+    ///
+    /// assert_eq!(cbuf.pop_back(), Some(2));
+    /// assert_eq!(cbuf.pop_back(), Some(1));
+    /// assert_eq!(cbuf.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
         }
+
+        let index = (self.start + self.size - 1) % self.capacity;
+        let value = self.take(index);
+        self.size -= 1;
+        Some(value)
     }
 
-    /// Extracts a slice containing the entire buffer.
-    pub fn as_slice(&self) -> &[T] {
-        if self.vec.len() < self.capacity {
-            self.vec.as_slice()
-        }else{
-            &self.vec.as_slice()[self.cur_start..self.cur_start + self.capacity]
+    /// Returns a reference to the front element, or `None` if the buffer is empty.
+    pub fn front(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[0])
         }
     }
 
-    /// Extracts a mutable slice of the entire buffer.
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        if self.vec.len() < self.capacity {
-            self.vec.as_mut_slice()
-        }else{
-            &mut self.vec.as_mut_slice()[self.cur_start..self.cur_start + self.capacity]
+    /// Returns a reference to the back element, or `None` if the buffer is empty.
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&self[self.size - 1])
         }
     }
 
-    /// Returns an iterator over the buffer's contents.
-    /// The iterator goes from the most recently pushed items to the oldest ones.
+    /// Returns a mutable reference to the front element, or `None` if the buffer is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(&mut self[0])
+        }
+    }
+
+    /// Returns a mutable reference to the back element, or `None` if the buffer is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            None
+        } else {
+            let index = self.size - 1;
+            Some(&mut self[index])
+        }
+    }
+
+    /// Takes ownership of the value stored at `index`, leaving that slot
+    /// uninitialized. The caller is responsible for updating `start`/`size`
+    /// so the slot isn't considered live (and isn't dropped again).
+    fn take(&mut self, index: usize) -> T {
+        let slot = core::mem::replace(&mut self.buf[index], MaybeUninit::uninit());
+        unsafe { slot.assume_init() }
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// The elements are yielded in logical order (oldest to newest within
+    /// the range). If the `Drain` is dropped before it's fully iterated,
+    /// any remaining elements in the range are dropped and the buffer is
+    /// still left in a consistent state, mirroring `Vec::drain`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(5);
+    /// cbuf.push_back(1);
+    /// cbuf.push_back(2);
+    /// cbuf.push_back(3);
+    /// cbuf.push_back(4);
+    ///
+    /// let drained: Vec<i32> = cbuf.drain(1..3).collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(cbuf.iter().cloned().collect::<Vec<_>>(), vec![1, 4]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let (drain_start, drain_end) = resolve_range(range, self.size);
+        let orig_start = self.start;
+        let drain_len = drain_end - drain_start;
+        let tail_len = self.size - drain_end;
+
+        self.size = drain_start;
+
+        Drain {
+            buffer: self,
+            orig_start,
+            drain_start,
+            drain_len,
+            tail_len,
+            front: 0,
+            back: drain_len,
+        }
+    }
+
+    /// Removes all elements from the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push_back(1);
+    /// cbuf.push_back(2);
+    /// cbuf.clear();
+    /// assert!(cbuf.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.drain(..);
+    }
+
+    /// Returns the buffer's contents as two slices.
+    ///
+    /// The first slice holds the elements starting at the current front of
+    /// the buffer up to the last element or the end of the backing storage,
+    /// whichever comes first; the second slice
+    /// holds the remainder that wrapped around to the beginning. The second
+    /// slice is empty unless the buffer's contents wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circle_buffer::CircleBuffer;
+    ///
+    /// let mut cbuf: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+    /// cbuf.push(1);
+    /// cbuf.push(2);
+    /// cbuf.push(3);
+    /// cbuf.push(4);
+    ///
+    /// let (first, second) = cbuf.as_slices();
+    /// assert_eq!(first, &[2, 3]);
+    /// assert_eq!(second, &[4]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let end = core::cmp::min(self.start + self.size, self.capacity);
+        let first = unsafe { slice_assume_init_ref(&self.buf[self.start..end]) };
+        let overflow = self.start + self.size - end;
+        let second = unsafe { slice_assume_init_ref(&self.buf[0..overflow]) };
+        (first, second)
+    }
+
+    /// Returns the buffer's contents as two mutable slices.
+    ///
+    /// See [`CircleBuffer::as_slices`] for how the buffer's contents are
+    /// split between the two slices.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let end = core::cmp::min(self.start + self.size, self.capacity);
+        let overflow = self.start + self.size - end;
+        let (wrapped, rest) = self.buf.split_at_mut(self.start);
+        let first = unsafe { slice_assume_init_mut(&mut rest[..end - self.start]) };
+        let second = unsafe { slice_assume_init_mut(&mut wrapped[..overflow]) };
+        (first, second)
+    }
+
+    /// Returns an iterator over the buffer's contents, from oldest to newest.
     ///
     /// # Examples
     ///
@@ -172,12 +472,12 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// let add2: Vec<i32> = buffer.iter().map(|x| x + 2).collect();
     /// assert_eq!(add2, vec![5, 6, 7]);
     /// ```
-    pub fn iter(&self) -> Iter<T> {
-        self.vec[self.cur_start..self.cur_start + self.len()].iter()
+    pub fn iter(&self) -> core::iter::Chain<slice::Iter<'_, T>, slice::Iter<'_, T>> {
+        let (first, second) = self.as_slices();
+        first.iter().chain(second.iter())
     }
 
-    /// Returns a mutable iterator over the buffer's contents.
-    /// The iterator goes from the most recently pushed items to the oldest ones.
+    /// Returns a mutable iterator over the buffer's contents, from oldest to newest.
     ///
     /// # Examples
     ///
@@ -191,38 +491,310 @@ impl<T> CircleBuffer<T> where T: Clone {
     /// for x in buffer.iter_mut() {
     ///     *x += 1;
     /// }
-    /// assert_eq!(buffer.as_slice(), &[2, 3, 4]);
     ///
     /// buffer.push(4);
     /// buffer.push(5);
     /// for x in buffer.iter_mut() {
     ///     *x += 2;
     /// }
-    /// assert_eq!(buffer.as_slice(), &[6, 6, 7]);
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        let end_index = self.cur_start + self.len();
-        self.vec[self.cur_start..end_index].iter_mut()
+    pub fn iter_mut(&mut self) -> core::iter::Chain<slice::IterMut<'_, T>, slice::IterMut<'_, T>> {
+        let (first, second) = self.as_mut_slices();
+        first.iter_mut().chain(second.iter_mut())
     }
 }
 
-impl<T> Index<usize> for CircleBuffer<T> where T: Clone {
+#[cfg(feature = "alloc")]
+impl<T> Index<usize> for CircleBuffer<T> {
     type Output = T;
 
     fn index(&self, index: usize) -> &T {
-        assert!(index < self.vec.len());
-        &self.as_slice()[index]
+        assert!(index < self.size);
+        let (first, second) = self.as_slices();
+        if index < first.len() {
+            &first[index]
+        } else {
+            &second[index - first.len()]
+        }
     }
 }
 
-impl<T> IndexMut<usize> for CircleBuffer<T> where T: Clone {
+#[cfg(feature = "alloc")]
+impl<T> IndexMut<usize> for CircleBuffer<T> {
     fn index_mut(&mut self, index: usize) -> &mut T {
-        assert!(index < self.vec.len());
-        &mut self.as_mut_slice()[index]
+        assert!(index < self.size);
+        let (first, second) = self.as_mut_slices();
+        let first_len = first.len();
+        if index < first_len {
+            &mut first[index]
+        } else {
+            &mut second[index - first_len]
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Drop for CircleBuffer<T> {
+    fn drop(&mut self) {
+        for i in 0..self.size {
+            let index = (self.start + i) % self.capacity;
+            unsafe {
+                core::ptr::drop_in_place(self.buf[index].as_mut_ptr());
+            }
+        }
+    }
+}
+
+/// Serializes the buffer's logical contents, oldest to newest (the same
+/// order as [`CircleBuffer::iter`]), as a sequence. The capacity and the
+/// internal wrap point are not part of the encoding.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<T: Serialize> Serialize for CircleBuffer<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.size))?;
+        for item in self.iter() {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a sequence into a `CircleBuffer<T>` whose capacity equals
+/// the number of elements decoded.
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for CircleBuffer<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(CircleBufferVisitor {
+            marker: core::marker::PhantomData,
+        })
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+struct CircleBufferVisitor<T> {
+    marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de, T: Deserialize<'de>> Visitor<'de> for CircleBufferVisitor<T> {
+    type Value = CircleBuffer<T>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a sequence of elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            items.push(value);
+        }
+
+        let mut buffer = CircleBuffer::with_capacity(items.len());
+        for item in items {
+            buffer.push_back(item);
+        }
+        Ok(buffer)
+    }
+}
+
+/// Resolves a `RangeBounds<usize>` against a collection of length `len` into
+/// a concrete `[start, end)`, panicking on an out-of-bounds or inverted range.
+#[cfg(feature = "alloc")]
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n + 1,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "drain start is after drain end");
+    assert!(end <= len, "drain end is out of bounds");
+    (start, end)
+}
+
+/// A draining iterator over a range of a [`CircleBuffer`]'s elements.
+///
+/// Created by [`CircleBuffer::drain`]. Dropping a `Drain` (whether it was
+/// fully iterated or not) removes the whole range from the buffer.
+#[cfg(feature = "alloc")]
+pub struct Drain<'a, T> {
+    buffer: &'a mut CircleBuffer<T>,
+    orig_start: usize,
+    drain_start: usize,
+    drain_len: usize,
+    tail_len: usize,
+    front: usize,
+    back: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Drain<'a, T> {
+    fn physical_index(&self, logical_offset: usize) -> usize {
+        (self.orig_start + self.drain_start + logical_offset) % self.buffer.capacity
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.physical_index(self.front);
+        self.front += 1;
+        Some(self.buffer.take(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.physical_index(self.back);
+        Some(self.buffer.take(index))
     }
 }
 
-#[cfg(test)]
+#[cfg(feature = "alloc")]
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // Drop whatever the caller never iterated over.
+        while self.front < self.back {
+            let index = self.physical_index(self.front);
+            self.front += 1;
+            unsafe {
+                core::ptr::drop_in_place(self.buffer.buf[index].as_mut_ptr());
+            }
+        }
+
+        // Shift the untouched tail down to close the gap the drain left.
+        for k in 0..self.tail_len {
+            let src = (self.orig_start + self.drain_start + self.drain_len + k) % self.buffer.capacity;
+            let dst = (self.orig_start + self.drain_start + k) % self.buffer.capacity;
+            if src != dst {
+                let value = unsafe { core::ptr::read(self.buffer.buf[src].as_ptr()) };
+                self.buffer.buf[dst] = MaybeUninit::new(value);
+            }
+        }
+
+        self.buffer.size = self.drain_start + self.tail_len;
+    }
+}
+
+#[cfg(feature = "std")]
+impl CircleBuffer<u8> {
+    /// Returns the backing storage as a raw `&mut [u8]`, ignoring which
+    /// slots are currently considered live. `u8` has no destructor, so
+    /// overwriting a slot in place never needs to drop the old byte.
+    fn raw_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr() as *mut u8, self.capacity) }
+    }
+}
+
+/// Writes bytes into the ring, overwriting the oldest bytes once full.
+/// `write` always reports the full length written; it never blocks and
+/// returns `Ok(0)` only for empty input, so [`std::io::Write::write_all`] is just a thin wrapper.
+#[cfg(feature = "std")]
+impl io::Write for CircleBuffer<u8> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let written = data.len();
+        if self.capacity == 0 || data.is_empty() {
+            return Ok(written);
+        }
+
+        if data.len() >= self.capacity {
+            let tail = &data[data.len() - self.capacity..];
+            self.raw_mut().copy_from_slice(tail);
+            self.start = 0;
+            self.size = self.capacity;
+            return Ok(written);
+        }
+
+        // Fill any free space first, then overwrite from the front.
+        let free = self.capacity - self.size;
+        let fill_len = data.len().min(free);
+        let (to_fill, to_overwrite) = data.split_at(fill_len);
+
+        let capacity = self.capacity;
+        let start = self.start;
+        let size = self.size;
+        let raw = self.raw_mut();
+        copy_wrapping(raw, (start + size) % capacity, to_fill);
+        self.size += fill_len;
+
+        if !to_overwrite.is_empty() {
+            let overwrite_start = self.start;
+            let raw = self.raw_mut();
+            copy_wrapping(raw, overwrite_start, to_overwrite);
+            self.start = (self.start + to_overwrite.len()) % capacity;
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Copies `data` into `raw` starting at `offset`, wrapping around the end
+/// of `raw` back to the beginning. `data.len()` must not exceed `raw.len()`.
+#[cfg(feature = "std")]
+fn copy_wrapping(raw: &mut [u8], offset: usize, data: &[u8]) {
+    let first_len = (raw.len() - offset).min(data.len());
+    raw[offset..offset + first_len].copy_from_slice(&data[..first_len]);
+    raw[..data.len() - first_len].copy_from_slice(&data[first_len..]);
+}
+
+/// Reads bytes from the front of the ring, removing them as they're read.
+#[cfg(feature = "std")]
+impl io::Read for CircleBuffer<u8> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let to_read = buf.len().min(self.size);
+        let (first, second) = self.as_slices();
+        let first_len = first.len().min(to_read);
+        buf[..first_len].copy_from_slice(&first[..first_len]);
+        let second_len = to_read - first_len;
+        buf[first_len..to_read].copy_from_slice(&second[..second_len]);
+
+        self.start = (self.start + to_read) % self.capacity.max(1);
+        self.size -= to_read;
+        Ok(to_read)
+    }
+}
+
+/// `fill_buf` exposes the first contiguous readable run; `consume` then
+/// advances the front by that many bytes, same as other `BufRead` sources.
+#[cfg(feature = "std")]
+impl io::BufRead for CircleBuffer<u8> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.as_slices().0)
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.start = (self.start + amt) % self.capacity.max(1);
+        self.size -= amt;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -230,39 +802,19 @@ mod tests {
     fn test_push() {
         let mut buffer = CircleBuffer::with_capacity(3);
         buffer.push(1);
-        assert_eq!(&[1], buffer.as_slice());
+        assert_eq!((&[1][..], &[][..]), buffer.as_slices());
         buffer.push(2);
-        assert_eq!(&[1, 2], buffer.as_slice());
+        assert_eq!((&[1, 2][..], &[][..]), buffer.as_slices());
         buffer.push(3);
-        assert_eq!(&[1, 2, 3], buffer.as_slice());
+        assert_eq!((&[1, 2, 3][..], &[][..]), buffer.as_slices());
         buffer.push(4);
-        assert_eq!(&[2, 3, 4], buffer.as_slice());
+        assert_eq!((&[2, 3][..], &[4][..]), buffer.as_slices());
         buffer.push(5);
-        assert_eq!(&[3, 4, 5], buffer.as_slice());
+        assert_eq!((&[3][..], &[4, 5][..]), buffer.as_slices());
         buffer.push(6);
-        assert_eq!(&[4, 5, 6], buffer.as_slice());
+        assert_eq!((&[4, 5, 6][..], &[][..]), buffer.as_slices());
         buffer.push(7);
-        assert_eq!(&[5, 6, 7], buffer.as_slice());
-        buffer.push(8);
-        assert_eq!(&[6, 7, 8], buffer.as_slice());
-        buffer.push(9);
-        assert_eq!(&[7, 8, 9], buffer.as_slice());
-        buffer.push(10);
-        assert_eq!(&[8, 9, 10], buffer.as_slice());
-        buffer.push(11);
-        assert_eq!(&[9, 10, 11], buffer.as_slice());
-        buffer.push(12);
-        assert_eq!(&[10, 11, 12], buffer.as_slice());
-        buffer.push(13);
-        assert_eq!(&[11, 12, 13], buffer.as_slice());
-        buffer.push(14);
-        assert_eq!(&[12, 13, 14], buffer.as_slice());
-        buffer.push(15);
-        assert_eq!(&[13, 14, 15], buffer.as_slice());
-        buffer.push(16);
-        assert_eq!(&[14, 15, 16], buffer.as_slice());
-        buffer.push(17);
-        assert_eq!(&[15, 16, 17], buffer.as_slice());
+        assert_eq!((&[5, 6][..], &[7][..]), buffer.as_slices());
     }
 
     #[test]
@@ -374,13 +926,309 @@ mod tests {
         for x in buffer.iter_mut() {
             *x += 1;
         }
-        assert_eq!(buffer.as_slice(), &[2, 3, 4]);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![2, 3, 4]);
 
         buffer.push(4);
         buffer.push(5);
         for x in buffer.iter_mut() {
             *x += 2;
         }
-        assert_eq!(buffer.as_slice(), &[6, 6, 7]);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![6, 6, 7]);
+    }
+
+    #[test]
+    fn test_drop_runs_for_every_live_element() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(RefCell::new(0));
+
+        struct DropCounter(Rc<RefCell<i32>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        {
+            let mut buffer = CircleBuffer::with_capacity(3);
+            buffer.push(DropCounter(drops.clone()));
+            buffer.push(DropCounter(drops.clone()));
+            buffer.push(DropCounter(drops.clone()));
+            buffer.push(DropCounter(drops.clone()));
+        }
+
+        assert_eq!(*drops.borrow(), 4);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut buffer = CircleBuffer::with_capacity(3);
+        buffer.push_front(1);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![1]);
+        buffer.push_front(2);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![2, 1]);
+        buffer.push_front(3);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![3, 2, 1]);
+        buffer.push_front(4);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut buffer = CircleBuffer::with_capacity(3);
+        assert_eq!(buffer.pop_front(), None);
+
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(buffer.len(), 1);
+        buffer.push_back(4);
+        buffer.push_back(5);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(buffer.pop_front(), Some(3));
+        assert_eq!(buffer.pop_front(), Some(4));
+        assert_eq!(buffer.pop_front(), Some(5));
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut buffer = CircleBuffer::with_capacity(3);
+        assert_eq!(buffer.pop_back(), None);
+
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4);
+        assert_eq!(buffer.pop_back(), Some(4));
+        assert_eq!(buffer.pop_back(), Some(3));
+        assert_eq!(buffer.pop_back(), Some(2));
+        assert_eq!(buffer.pop_back(), None);
+    }
+
+    #[test]
+    fn test_front_and_back() {
+        let mut buffer: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+        assert_eq!(buffer.front(), None);
+        assert_eq!(buffer.back(), None);
+
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        assert_eq!(buffer.front(), Some(&1));
+        assert_eq!(buffer.back(), Some(&3));
+
+        *buffer.front_mut().unwrap() += 10;
+        *buffer.back_mut().unwrap() += 20;
+        assert_eq!(buffer.front(), Some(&11));
+        assert_eq!(buffer.back(), Some(&23));
+    }
+
+    #[test]
+    fn test_push_front_drops_overwritten_back() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(RefCell::new(0));
+
+        struct DropCounter(Rc<RefCell<i32>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        let mut buffer = CircleBuffer::with_capacity(2);
+        buffer.push_back(DropCounter(drops.clone()));
+        buffer.push_back(DropCounter(drops.clone()));
+        buffer.push_front(DropCounter(drops.clone()));
+        assert_eq!(*drops.borrow(), 1);
+    }
+
+    #[test]
+    fn test_push_zero_capacity_is_a_noop() {
+        let mut buffer: CircleBuffer<i32> = CircleBuffer::with_capacity(0);
+        buffer.push_back(1);
+        buffer.push_front(2);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_write() {
+        use std::io::Write;
+
+        let mut buffer: CircleBuffer<u8> = CircleBuffer::with_capacity(4);
+        assert_eq!(buffer.write(b"ab").unwrap(), 2);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"ab");
+
+        assert_eq!(buffer.write(b"cdef").unwrap(), 4);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"cdef");
+
+        assert_eq!(buffer.write(b"ghijklm").unwrap(), 7);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"jklm");
+    }
+
+    #[test]
+    fn test_read() {
+        use std::io::{Read, Write};
+
+        let mut buffer: CircleBuffer<u8> = CircleBuffer::with_capacity(4);
+        buffer.write_all(b"abcdef").unwrap();
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"cdef");
+
+        let mut out = [0u8; 3];
+        assert_eq!(buffer.read(&mut out).unwrap(), 3);
+        assert_eq!(&out, b"cde");
+        assert_eq!(buffer.len(), 1);
+
+        let mut out = [0u8; 3];
+        assert_eq!(buffer.read(&mut out).unwrap(), 1);
+        assert_eq!(&out[..1], b"f");
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_buf_read() {
+        use std::io::{BufRead, Write};
+
+        let mut buffer: CircleBuffer<u8> = CircleBuffer::with_capacity(4);
+        buffer.write_all(b"wxyz").unwrap();
+        buffer.write_all(b"ab").unwrap();
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"yzab");
+
+        let first = buffer.fill_buf().unwrap().to_vec();
+        assert_eq!(first, b"yz");
+        buffer.consume(first.len());
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), b"ab");
+
+        let second = buffer.fill_buf().unwrap().to_vec();
+        assert_eq!(second, b"ab");
+        buffer.consume(second.len());
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_full_range() {
+        let mut buffer = CircleBuffer::with_capacity(4);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4);
+        buffer.push_back(5);
+
+        let drained: Vec<i32> = buffer.drain(..).collect();
+        assert_eq!(drained, vec![2, 3, 4, 5]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_middle_range() {
+        let mut buffer = CircleBuffer::with_capacity(5);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4);
+        buffer.push_back(5);
+
+        let drained: Vec<i32> = buffer.drain(1..3).collect();
+        assert_eq!(drained, vec![2, 3]);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn test_drain_wrapped_range() {
+        let mut buffer = CircleBuffer::with_capacity(3);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4); // wraps: logical [2, 3, 4]
+
+        let drained: Vec<i32> = buffer.drain(1..).collect();
+        assert_eq!(drained, vec![3, 4]);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[test]
+    fn test_drain_double_ended() {
+        let mut buffer = CircleBuffer::with_capacity(5);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4);
+
+        let mut drain = buffer.drain(..);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next_back(), Some(3));
+        assert_eq!(drain.next(), None);
+        assert_eq!(drain.next_back(), None);
+        drop(drain);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_dropped_early_still_removes_range() {
+        let mut buffer = CircleBuffer::with_capacity(5);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4);
+        buffer.push_back(5);
+
+        {
+            let mut drain = buffer.drain(1..4);
+            assert_eq!(drain.next(), Some(2));
+            // `drain` is dropped here without exhausting the iterator.
+        }
+
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_drain_drops_unyielded_elements() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drops = Rc::new(RefCell::new(0));
+
+        struct DropCounter(Rc<RefCell<i32>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        let mut buffer = CircleBuffer::with_capacity(4);
+        buffer.push_back(DropCounter(drops.clone()));
+        buffer.push_back(DropCounter(drops.clone()));
+        buffer.push_back(DropCounter(drops.clone()));
+
+        buffer.drain(..2);
+        assert_eq!(*drops.borrow(), 2);
+
+        buffer.clear();
+        assert_eq!(*drops.borrow(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let mut buffer: CircleBuffer<i32> = CircleBuffer::with_capacity(3);
+        buffer.push_back(1);
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_back(4); // wraps: logical [2, 3, 4]
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        assert_eq!(json, "[2,3,4]");
+
+        let restored: CircleBuffer<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.capacity(), 3);
+        assert_eq!(restored.iter().cloned().collect::<Vec<_>>(), vec![2, 3, 4]);
     }
 }